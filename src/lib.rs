@@ -0,0 +1,7 @@
+//! intrico: a small quantum circuit simulator.
+
+pub mod qsim_core;
+
+pub use qsim_core::circuit::QuantumCircuit;
+pub use qsim_core::gate::QuantumGate;
+pub use qsim_core::qubit::Qubit;
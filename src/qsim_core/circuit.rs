@@ -1,17 +1,23 @@
 use std::fmt;
+use num_complex::Complex;
 use crate::qsim_core::gate::{QuantumGate, GateOp};
+use crate::qsim_core::measurement::Basis;
+use crate::qsim_core::operation::Operation;
+use crate::qsim_core::qasm::{self, QasmError};
 use crate::qsim_core::qubit::Qubit;
+use crate::qsim_core::state_vector::StateVector;
+use crate::qsim_core::zyz::{self, ZyzDecomposition};
 
 /// Represents a quantum circuit that can be built and executed
-/// 
+///
 /// A quantum circuit is a sequence of quantum gates applied to one or more qubits.
 /// This implementation allows for building circuits incrementally and executing them
 /// on a set of qubits.
 pub struct QuantumCircuit {
     /// The number of qubits in the circuit
     num_qubits: usize,
-    /// The sequence of gate operations to apply
-    operations: Vec<GateOp>,
+    /// The sequence of operations to apply
+    operations: Vec<Operation>,
 }
 
 impl QuantumCircuit {
@@ -74,6 +80,33 @@ impl QuantumCircuit {
         self.add_gate(QuantumGate::T, target);
     }
 
+    /// Applies a rotation of `theta` radians about the X axis to the specified qubit
+    pub fn rx(&mut self, theta: f64, target: usize) {
+        self.add_gate(QuantumGate::RX(theta), target);
+    }
+
+    /// Applies a rotation of `theta` radians about the Y axis to the specified qubit
+    pub fn ry(&mut self, theta: f64, target: usize) {
+        self.add_gate(QuantumGate::RY(theta), target);
+    }
+
+    /// Applies a rotation of `theta` radians about the Z axis to the specified qubit
+    pub fn rz(&mut self, theta: f64, target: usize) {
+        self.add_gate(QuantumGate::RZ(theta), target);
+    }
+
+    /// Applies a phase shift of `lambda` radians to the specified qubit
+    pub fn u1(&mut self, lambda: f64, target: usize) {
+        self.add_gate(QuantumGate::U1(lambda), target);
+    }
+
+    /// Applies the general single-qubit unitary `U3(theta, phi, lambda)` to the specified qubit
+    ///
+    /// `u1` is the special case `U3(0, 0, lambda)` up to global phase.
+    pub fn u3(&mut self, theta: f64, phi: f64, lambda: f64, target: usize) {
+        self.add_gate(QuantumGate::U3(theta, phi, lambda), target);
+    }
+
     /// Applies a CNOT gate with the specified control and target qubits
     /// 
     /// # Arguments
@@ -86,35 +119,137 @@ impl QuantumCircuit {
         if control >= self.num_qubits || target >= self.num_qubits {
             panic!("Qubit index out of bounds for circuit with {} qubits", self.num_qubits);
         }
-        let step = self.operations.iter()
-            .filter(|op| op.target == target)
-            .map(|op| op.step)
-            .max()
-            .map(|s| s + 1)
-            .unwrap_or(0);
-        self.operations.push(GateOp::controlled(QuantumGate::CNOT, control, target, step));
+        let step = self.next_step(&[control, target]);
+        self.operations.push(Operation::Gate(GateOp::controlled(QuantumGate::CNOT, control, target, step)));
+    }
+
+    /// Measures `qubit` in the given basis
+    ///
+    /// When run via `execute_statevector`, this collapses the joint state
+    /// and records the outcome, retrievable from `StateVector::classical_bits`.
+    ///
+    /// # Panics
+    /// Panics if the qubit index is out of bounds
+    pub fn measure(&mut self, qubit: usize, basis: Basis) {
+        self.push_measurement_op(qubit, |step| Operation::Measure { qubit, basis, step });
+    }
+
+    /// Resets `qubit` to |0>, collapsing it without recording a classical outcome
+    ///
+    /// # Panics
+    /// Panics if the qubit index is out of bounds
+    pub fn reset(&mut self, qubit: usize) {
+        self.push_measurement_op(qubit, |step| Operation::Reset { qubit, step });
+    }
+
+    /// Samples a measurement outcome for `qubit` in the given basis without
+    /// collapsing the state
+    ///
+    /// When run via `execute_statevector`, the sampled outcome is appended
+    /// to the returned state's `StateVector::peeks`, in order; it is never
+    /// written to the classical register.
+    ///
+    /// # Panics
+    /// Panics if the qubit index is out of bounds
+    pub fn peek(&mut self, qubit: usize, basis: Basis) {
+        self.push_measurement_op(qubit, |step| Operation::Peek { qubit, basis, step });
+    }
+
+    /// Applies `build`'s gate(s) conditionally, firing only when the classical
+    /// register bits at `bits` (as written by an earlier `measure`), read
+    /// most-significant first, equal `value`
+    ///
+    /// `build` is run against a scratch circuit so its gates can be captured
+    /// and wrapped; each gate it adds becomes its own `ConditionalGate`
+    /// operation in this circuit, guarded by the same `bits`/`value`. This is
+    /// the primitive behind teleportation and error-correction circuits,
+    /// which need a gate's application to depend on a prior measurement.
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    /// use intrico::qsim_core::measurement::Basis;
+    ///
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.measure(0, Basis::Z);
+    /// qc.c_if(&[0], 1, |qc| qc.x(1));  // flip qubit 1 iff qubit 0 measured |1>
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `bits` is empty, if any index in `bits` is out of bounds, or
+    /// if `build` schedules anything other than gate operations.
+    pub fn c_if(&mut self, bits: &[usize], value: usize, build: impl FnOnce(&mut QuantumCircuit)) {
+        if bits.is_empty() {
+            panic!("c_if requires at least one classical bit index");
+        }
+        for &bit in bits {
+            if bit >= self.num_qubits {
+                panic!("Qubit index {} is out of bounds for circuit with {} qubits", bit, self.num_qubits);
+            }
+        }
+
+        let mut scratch = QuantumCircuit::new(self.num_qubits);
+        build(&mut scratch);
+
+        for op in scratch.operations {
+            let Operation::Gate(gate_op) = op else {
+                panic!("c_if only supports gate operations");
+            };
+            let step = match gate_op.control {
+                Some(control) => self.next_step(&[control, gate_op.target]),
+                None => self.next_step(&[gate_op.target]),
+            };
+            self.operations.push(Operation::ConditionalGate {
+                bits: bits.to_vec(),
+                value,
+                gate: GateOp { step, ..gate_op },
+            });
+        }
     }
 
     /// Adds a gate operation to the circuit
-    /// 
+    ///
     /// # Arguments
     /// * `gate` - The quantum gate to apply
     /// * `target` - The index of the qubit to apply the gate to
-    /// 
+    ///
     /// # Panics
     /// Panics if the target qubit index is out of bounds
     fn add_gate(&mut self, gate: QuantumGate, target: usize) {
         if target >= self.num_qubits {
-            panic!("Qubit index {} is out of bounds for circuit with {} qubits", 
+            panic!("Qubit index {} is out of bounds for circuit with {} qubits",
                    target, self.num_qubits);
         }
-        let step = self.operations.iter()
-            .filter(|op| op.target == target)
-            .map(|op| op.step)
+        let step = self.next_step(&[target]);
+        self.operations.push(Operation::Gate(GateOp::new(gate, target, step)));
+    }
+
+    /// Schedules a measurement-related operation on `qubit`, built by `make_op`
+    ///
+    /// # Panics
+    /// Panics if the qubit index is out of bounds
+    fn push_measurement_op(&mut self, qubit: usize, make_op: impl FnOnce(usize) -> Operation) {
+        if qubit >= self.num_qubits {
+            panic!("Qubit index {} is out of bounds for circuit with {} qubits", qubit, self.num_qubits);
+        }
+        let step = self.next_step(&[qubit]);
+        self.operations.push(make_op(step));
+    }
+
+    /// The next free step across every wire in `wires`: one past the highest
+    /// step already scheduled on any of them, whether that wire carried an
+    /// operation as its target or, for controlled gates, as its control.
+    /// Callers pass every wire a new operation touches so that, e.g., a CNOT
+    /// never lands on a step already occupied by a prior gate on its control
+    /// qubit.
+    fn next_step(&self, wires: &[usize]) -> usize {
+        self.operations.iter()
+            .filter(|op| wires.iter().any(|&w| op.target() == w || op.control() == Some(w)))
+            .map(|op| op.step())
             .max()
             .map(|s| s + 1)
-            .unwrap_or(0);
-        self.operations.push(GateOp::new(gate, target, step));
+            .unwrap_or(0)
     }
 
     /// Executes the circuit on a set of qubits
@@ -137,15 +272,180 @@ impl QuantumCircuit {
     /// ```
     pub fn execute(&self, qubits: &mut [Qubit]) {
         if qubits.len() != self.num_qubits {
-            panic!("Number of qubits ({}) doesn't match circuit size ({})", 
+            panic!("Number of qubits ({}) doesn't match circuit size ({})",
                    qubits.len(), self.num_qubits);
         }
 
         for op in &self.operations {
-            qubits[op.target].apply(op.gate);
+            match op {
+                Operation::Gate(gate_op) => qubits[gate_op.target].apply(gate_op.gate),
+                Operation::Measure { .. } | Operation::Reset { .. } | Operation::Peek { .. } => {
+                    panic!("measurement operations require execute_statevector, which tracks a classical register");
+                }
+                Operation::ConditionalGate { .. } => {
+                    panic!("c_if operations require execute_statevector, which tracks a classical register");
+                }
+            }
         }
     }
 
+    /// Executes the circuit on a joint state vector instead of independent qubits
+    ///
+    /// Unlike `execute`, which applies each gate to its own `Qubit` in isolation,
+    /// this builds a single `2^num_qubits`-amplitude `StateVector` and applies
+    /// every operation against it. This is what correctly entangles qubits for
+    /// gates like `cnot`, and it's the only path that supports `measure`,
+    /// `reset`, and `peek`. Classical measurement outcomes can be read back
+    /// from the returned state via `StateVector::classical_bits`, and
+    /// `peek` outcomes via `StateVector::peeks`.
+    ///
+    /// # Examples
+    /// ```
+    /// use intrico::QuantumCircuit;
+    ///
+    /// let mut qc = QuantumCircuit::new(2);
+    /// qc.h(0);
+    /// qc.cnot(0, 1);
+    ///
+    /// let state = qc.execute_statevector();
+    /// ```
+    pub fn execute_statevector(&self) -> StateVector {
+        let mut state = StateVector::new(self.num_qubits);
+        for op in &self.operations {
+            match op {
+                Operation::Gate(gate_op) => state.apply_op(gate_op),
+                Operation::Measure { qubit, basis, .. } => {
+                    state.measure(*qubit, *basis);
+                }
+                Operation::Reset { qubit, .. } => state.reset(*qubit),
+                Operation::Peek { qubit, basis, .. } => {
+                    state.peek(*qubit, *basis);
+                }
+                Operation::ConditionalGate { bits, value, gate } => {
+                    if classical_bits_match(state.classical_bits(), bits, *value) {
+                        state.apply_op(gate);
+                    }
+                }
+            }
+        }
+        state
+    }
+
+    /// Collapses runs of consecutive single-qubit gates on the same wire into one equivalent gate
+    ///
+    /// Gates execute in the order they were added; a maximal chain of
+    /// single-qubit operations on a qubit (broken whenever that qubit takes
+    /// part in `cnot` or a measurement-related operation) is multiplied into
+    /// a single 2x2 unitary and re-emitted via a ZYZ Euler decomposition as
+    /// `rz`/`ry`/`rz`, collapsing to fewer gates when the chain is
+    /// equivalent to a pure phase rotation or to `ry(pi)`. This reduces
+    /// circuit depth before simulation.
+    pub fn optimize(&mut self) {
+        let mut rebuilt = QuantumCircuit::new(self.num_qubits);
+        let mut pending: Vec<Option<[[Complex<f64>; 2]; 2]>> = vec![None; self.num_qubits];
+
+        for op in &self.operations {
+            match op {
+                Operation::Gate(gate_op) if gate_op.control.is_none() => {
+                    let acc = pending[gate_op.target].get_or_insert_with(zyz::identity);
+                    *acc = zyz::mat_mul(gate_op.gate.matrix(), *acc);
+                }
+                Operation::Gate(gate_op) => {
+                    let control = gate_op.control.expect("CNOT operation is missing its control qubit");
+                    flush_chain(&mut rebuilt, &mut pending, control);
+                    flush_chain(&mut rebuilt, &mut pending, gate_op.target);
+                    rebuilt.cnot(control, gate_op.target);
+                }
+                Operation::Measure { qubit, basis, .. } => {
+                    flush_chain(&mut rebuilt, &mut pending, *qubit);
+                    rebuilt.measure(*qubit, *basis);
+                }
+                Operation::Reset { qubit, .. } => {
+                    flush_chain(&mut rebuilt, &mut pending, *qubit);
+                    rebuilt.reset(*qubit);
+                }
+                Operation::Peek { qubit, basis, .. } => {
+                    flush_chain(&mut rebuilt, &mut pending, *qubit);
+                    rebuilt.peek(*qubit, *basis);
+                }
+                Operation::ConditionalGate { bits, value, gate } => {
+                    if let Some(control) = gate.control {
+                        flush_chain(&mut rebuilt, &mut pending, control);
+                    }
+                    flush_chain(&mut rebuilt, &mut pending, gate.target);
+                    let step = match gate.control {
+                        Some(control) => rebuilt.next_step(&[control, gate.target]),
+                        None => rebuilt.next_step(&[gate.target]),
+                    };
+                    rebuilt.operations.push(Operation::ConditionalGate {
+                        bits: bits.clone(),
+                        value: *value,
+                        gate: GateOp { step, ..*gate },
+                    });
+                }
+            }
+        }
+        for qubit in 0..self.num_qubits {
+            flush_chain(&mut rebuilt, &mut pending, qubit);
+        }
+
+        self.operations = rebuilt.operations;
+    }
+
+    /// Serializes this circuit to OpenQASM 2.0 source
+    ///
+    /// Emits the standard header, a `qreg` declaration (and a `creg`, if any
+    /// qubit is measured), and one line per operation ordered by `step`.
+    /// `measure`/`reset` round-trip through `from_openqasm` when the
+    /// measurement basis is `Z`; `X`/`Y`-basis `measure` and the
+    /// non-destructive `peek` have no OpenQASM 2.0 equivalent and are
+    /// emitted as comments only.
+    pub fn to_openqasm(&self) -> String {
+        let mut out = String::new();
+        out.push_str("OPENQASM 2.0;\n");
+        out.push_str("include \"qelib1.inc\";\n");
+        out.push_str(&format!("qreg q[{}];\n", self.num_qubits));
+        if self.operations.iter().any(|op| matches!(op, Operation::Measure { .. })) {
+            out.push_str(&format!("creg c[{}];\n", self.num_qubits));
+        }
+
+        let mut ordered: Vec<&Operation> = self.operations.iter().collect();
+        ordered.sort_by_key(|op| op.step());
+
+        for op in ordered {
+            match op {
+                Operation::Gate(gate_op) => out.push_str(&gate_op_to_qasm(gate_op)),
+                Operation::Measure { qubit, basis: Basis::Z, .. } => {
+                    out.push_str(&format!("measure q[{0}] -> c[{0}];\n", qubit));
+                }
+                Operation::Measure { qubit, basis, .. } => {
+                    out.push_str(&format!("// measure q[{}] in {:?} basis (no OpenQASM 2.0 equivalent)\n", qubit, basis));
+                }
+                Operation::Reset { qubit, .. } => out.push_str(&format!("reset q[{}];\n", qubit)),
+                Operation::Peek { qubit, basis, .. } => {
+                    out.push_str(&format!("// peek q[{}] in {:?} basis (no OpenQASM 2.0 equivalent)\n", qubit, basis));
+                }
+                Operation::ConditionalGate { bits, value, gate } => {
+                    out.push_str(&format!(
+                        "// c_if({:?} == {}) {}",
+                        bits,
+                        value,
+                        gate_op_to_qasm(gate)
+                    ));
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Parses an OpenQASM 2.0 source string into a `QuantumCircuit`
+    ///
+    /// See `qsim_core::qasm::parse` for the supported subset.
+    pub fn from_openqasm(src: &str) -> Result<Self, QasmError> {
+        qasm::parse(src)
+    }
+
     /// Returns the number of qubits in the circuit
     pub fn num_qubits(&self) -> usize {
         self.num_qubits
@@ -157,14 +457,85 @@ impl QuantumCircuit {
     }
 
     /// Displays the quantum circuit in ASCII format to stdout
+    ///
+    /// Each qubit gets a wire line (`q0: ───`), with a blank spacer line
+    /// between consecutive wires. Gates are drawn in the column given by
+    /// their `step` field, and `cnot` is drawn as a `●` on the control wire
+    /// connected by a `│` through the spacer rows to a `⊕` on the target
+    /// wire. Column widths adapt to the longest gate label in that column.
     pub fn display(&self) {
         let height = 2 * self.num_qubits - 1;
+        let num_columns = self.operations.iter()
+            .map(|op| op.step())
+            .max()
+            .map(|s| s + 1)
+            .unwrap_or(0);
+
+        let mut column_widths = vec![1usize; num_columns];
+        for op in &self.operations {
+            let label_len = if op.control().is_some() {
+                1
+            } else {
+                op.diagram_symbol().chars().count()
+            };
+            if label_len > column_widths[op.step()] {
+                column_widths[op.step()] = label_len;
+            }
+        }
+
+        let prefix_width = (0..self.num_qubits)
+            .map(|q| format!("q{}: ", q).len())
+            .max()
+            .unwrap_or(0);
+
         let mut lines = vec![String::new(); height];
+        for q in 0..self.num_qubits {
+            lines[2 * q] = format!("{:<width$}", format!("q{}:", q), width = prefix_width);
+        }
+        for spacer in 0..self.num_qubits.saturating_sub(1) {
+            lines[2 * spacer + 1] = " ".repeat(prefix_width);
+        }
 
-        
-        
+        for (col, &width) in column_widths.iter().enumerate() {
+            let mut wire_symbols: Vec<Option<String>> = vec![None; self.num_qubits];
+            let mut spacer_has_bar = vec![false; self.num_qubits.saturating_sub(1)];
+
+            for op in self.operations.iter().filter(|op| op.step() == col) {
+                let target = op.target();
+                match op.control() {
+                    Some(control) => {
+                        wire_symbols[control] = Some("●".to_string());
+                        wire_symbols[target] = Some("⊕".to_string());
+                        let (lo, hi) = if control < target {
+                            (control, target)
+                        } else {
+                            (target, control)
+                        };
+                        spacer_has_bar[lo..hi].iter_mut().for_each(|has_bar| *has_bar = true);
+                    }
+                    None => {
+                        wire_symbols[target] = Some(op.diagram_symbol());
+                    }
+                }
+            }
+
+            for q in 0..self.num_qubits {
+                lines[2 * q].push_str("──");
+                match &wire_symbols[q] {
+                    Some(symbol) => lines[2 * q].push_str(&format!("{:─^width$}", symbol, width = width)),
+                    None => lines[2 * q].push_str(&"─".repeat(width)),
+                }
+            }
+            for spacer in 0..self.num_qubits.saturating_sub(1) {
+                lines[2 * spacer + 1].push_str("  ");
+                if spacer_has_bar[spacer] {
+                    lines[2 * spacer + 1].push_str(&format!("{:^width$}", "│", width = width));
+                } else {
+                    lines[2 * spacer + 1].push_str(&" ".repeat(width));
+                }
+            }
+        }
 
-        // Print the circuit
         for line in lines {
             println!("{}", line);
         }
@@ -173,10 +544,10 @@ impl QuantumCircuit {
 
 impl fmt::Display for QuantumCircuit {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "Quantum Circuit ({} qubits, {} operations):", 
+        writeln!(f, "Quantum Circuit ({} qubits, {} operations):",
                  self.num_qubits, self.num_operations())?;
         for (i, op) in self.operations.iter().enumerate() {
-            writeln!(f, "  {}. {} on qubit {} (Step: {})", i + 1, op.gate, op.target, op.step)?;
+            writeln!(f, "  {}. {} on qubit {} (Step: {})", i + 1, op.diagram_symbol(), op.target(), op.step())?;
         }
         Ok(())
     }
@@ -184,7 +555,111 @@ impl fmt::Display for QuantumCircuit {
 
 impl fmt::Debug for QuantumCircuit {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "QuantumCircuit {{ num_qubits: {}, operations: {:?} }}", 
+        write!(f, "QuantumCircuit {{ num_qubits: {}, operations: {:?} }}",
                self.num_qubits, self.operations)
     }
-} 
\ No newline at end of file
+}
+
+/// Checks a `ConditionalGate`'s guard against the classical register: true
+/// iff every bit in `bits` has been measured and, read most-significant
+/// first, the measured bits equal `value`.
+fn classical_bits_match(classical: &[Option<bool>], bits: &[usize], value: usize) -> bool {
+    bits.iter().enumerate().all(|(i, &bit)| {
+        let expected = (value >> (bits.len() - 1 - i)) & 1 == 1;
+        classical[bit] == Some(expected)
+    })
+}
+
+/// Flushes `qubit`'s pending fused unitary, if any, into `rebuilt` as its ZYZ decomposition.
+fn flush_chain(
+    rebuilt: &mut QuantumCircuit,
+    pending: &mut [Option<[[Complex<f64>; 2]; 2]>],
+    qubit: usize,
+) {
+    let Some(matrix) = pending[qubit].take() else {
+        return;
+    };
+    match zyz::decompose(matrix) {
+        ZyzDecomposition::SingleRz(angle) => rebuilt.rz(angle, qubit),
+        ZyzDecomposition::PiRotation(diff) => {
+            rebuilt.ry(std::f64::consts::PI, qubit);
+            rebuilt.rz(diff, qubit);
+        }
+        ZyzDecomposition::Full { lambda, theta, phi } => {
+            rebuilt.rz(lambda, qubit);
+            rebuilt.ry(theta, qubit);
+            rebuilt.rz(phi, qubit);
+        }
+    }
+}
+
+/// Renders a single `GateOp` as one OpenQASM 2.0 statement.
+fn gate_op_to_qasm(op: &GateOp) -> String {
+    match (op.gate, op.control) {
+        (QuantumGate::CNOT, Some(control)) => format!("cx q[{}],q[{}];\n", control, op.target),
+        (QuantumGate::CNOT, None) => panic!("CNOT operation is missing its control qubit"),
+        (QuantumGate::H, _) => format!("h q[{}];\n", op.target),
+        (QuantumGate::X, _) => format!("x q[{}];\n", op.target),
+        (QuantumGate::Y, _) => format!("y q[{}];\n", op.target),
+        (QuantumGate::Z, _) => format!("z q[{}];\n", op.target),
+        (QuantumGate::S, _) => format!("s q[{}];\n", op.target),
+        (QuantumGate::T, _) => format!("t q[{}];\n", op.target),
+        (QuantumGate::RX(theta), _) => format!("rx({}) q[{}];\n", theta, op.target),
+        (QuantumGate::RY(theta), _) => format!("ry({}) q[{}];\n", theta, op.target),
+        (QuantumGate::RZ(theta), _) => format!("rz({}) q[{}];\n", theta, op.target),
+        (QuantumGate::U1(lambda), _) => format!("u1({}) q[{}];\n", lambda, op.target),
+        (QuantumGate::U3(theta, phi, lambda), _) => {
+            format!("u3({}, {}, {}) q[{}];\n", theta, phi, lambda, op.target)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: Complex<f64>, expected: Complex<f64>) {
+        assert!(
+            (actual - expected).norm() < 1e-9,
+            "expected {:?}, got {:?}",
+            expected,
+            actual
+        );
+    }
+
+    /// `x(0)` deterministically collapses qubit 0 to |1> on measurement, so
+    /// `c_if(&[0], 1, ...)` must fire and flip qubit 1.
+    #[test]
+    fn c_if_fires_when_classical_bits_match() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.x(0);
+        qc.measure(0, Basis::Z);
+        qc.c_if(&[0], 1, |qc| qc.x(1));
+
+        let state = qc.execute_statevector();
+        let amps = state.amplitudes();
+        let one = Complex::new(1.0, 0.0);
+        let zero = Complex::new(0.0, 0.0);
+        for (i, &amp) in amps.iter().enumerate() {
+            assert_close(amp, if i == 0b11 { one } else { zero });
+        }
+    }
+
+    /// Same setup, but guarded on `value = 0`, which can never match a
+    /// measured |1>; the conditional gate must not fire.
+    #[test]
+    fn c_if_does_not_fire_when_classical_bits_mismatch() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.x(0);
+        qc.measure(0, Basis::Z);
+        qc.c_if(&[0], 0, |qc| qc.x(1));
+
+        let state = qc.execute_statevector();
+        let amps = state.amplitudes();
+        let one = Complex::new(1.0, 0.0);
+        let zero = Complex::new(0.0, 0.0);
+        for (i, &amp) in amps.iter().enumerate() {
+            assert_close(amp, if i == 0b10 { one } else { zero });
+        }
+    }
+}
\ No newline at end of file
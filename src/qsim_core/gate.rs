@@ -0,0 +1,183 @@
+use std::f64::consts::FRAC_1_SQRT_2;
+use std::fmt;
+
+use num_complex::Complex;
+
+/// The quantum gates supported by the simulator.
+///
+/// `H`, `X`, `Y`, `Z`, `S`, `T`, and `CNOT` are fixed-matrix gates. `RX`,
+/// `RY`, `RZ`, `U1`, and `U3` are parametric: they carry real-valued angles
+/// and build their matrix on the fly, which is what lets them express
+/// arbitrary single-qubit rotations. `CNOT` is a two-qubit gate; its control
+/// qubit is carried on the owning `GateOp` rather than here, since a
+/// `QuantumGate` value is also used to label single-qubit operations.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QuantumGate {
+    H,
+    X,
+    Y,
+    Z,
+    S,
+    T,
+    CNOT,
+    /// Rotation about the X axis by `theta` radians.
+    RX(f64),
+    /// Rotation about the Y axis by `theta` radians.
+    RY(f64),
+    /// Rotation about the Z axis by `theta` radians.
+    RZ(f64),
+    /// Phase shift by `lambda` radians: `diag(1, e^{i*lambda})`.
+    U1(f64),
+    /// The general single-qubit unitary parameterized by `(theta, phi, lambda)`.
+    U3(f64, f64, f64),
+}
+
+impl QuantumGate {
+    /// Returns the 2x2 unitary matrix (row-major) for this gate.
+    ///
+    /// # Panics
+    /// Panics for `CNOT`, which has no single-qubit matrix; callers apply it
+    /// as a controlled operation instead (see `StateVector::apply_op`).
+    pub fn matrix(&self) -> [[Complex<f64>; 2]; 2] {
+        let zero = Complex::new(0.0, 0.0);
+        let one = Complex::new(1.0, 0.0);
+        let frac = Complex::new(FRAC_1_SQRT_2, 0.0);
+        match self {
+            QuantumGate::H => [[frac, frac], [frac, -frac]],
+            QuantumGate::X => [[zero, one], [one, zero]],
+            QuantumGate::Y => [[zero, Complex::new(0.0, -1.0)], [Complex::new(0.0, 1.0), zero]],
+            QuantumGate::Z => [[one, zero], [zero, -one]],
+            QuantumGate::S => [[one, zero], [zero, Complex::new(0.0, 1.0)]],
+            QuantumGate::T => [[one, zero], [zero, Complex::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2)]],
+            QuantumGate::CNOT => panic!("CNOT has no single-qubit matrix"),
+            QuantumGate::RX(theta) => {
+                let (c, s) = ((theta / 2.0).cos(), (theta / 2.0).sin());
+                let neg_i_s = Complex::new(0.0, -s);
+                [[Complex::new(c, 0.0), neg_i_s], [neg_i_s, Complex::new(c, 0.0)]]
+            }
+            QuantumGate::RY(theta) => {
+                let (c, s) = ((theta / 2.0).cos(), (theta / 2.0).sin());
+                [[Complex::new(c, 0.0), Complex::new(-s, 0.0)], [Complex::new(s, 0.0), Complex::new(c, 0.0)]]
+            }
+            QuantumGate::RZ(theta) => [
+                [Complex::new(0.0, -theta / 2.0).exp(), zero],
+                [zero, Complex::new(0.0, theta / 2.0).exp()],
+            ],
+            QuantumGate::U1(lambda) => [[one, zero], [zero, Complex::new(0.0, *lambda).exp()]],
+            QuantumGate::U3(theta, phi, lambda) => {
+                let (c, s) = ((theta / 2.0).cos(), (theta / 2.0).sin());
+                [
+                    [Complex::new(c, 0.0), -Complex::new(0.0, *lambda).exp() * s],
+                    [Complex::new(0.0, *phi).exp() * s, Complex::new(0.0, phi + lambda).exp() * c],
+                ]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: Complex<f64>, expected: Complex<f64>) {
+        assert!(
+            (actual - expected).norm() < 1e-9,
+            "expected {:?}, got {:?}",
+            expected,
+            actual
+        );
+    }
+
+    fn assert_matrix_close(actual: [[Complex<f64>; 2]; 2], expected: [[Complex<f64>; 2]; 2]) {
+        for row in 0..2 {
+            for col in 0..2 {
+                assert_close(actual[row][col], expected[row][col]);
+            }
+        }
+    }
+
+    /// A zero-angle rotation about any axis is the identity.
+    #[test]
+    fn zero_angle_rotations_are_identity() {
+        let one = Complex::new(1.0, 0.0);
+        let zero = Complex::new(0.0, 0.0);
+        let identity = [[one, zero], [zero, one]];
+
+        assert_matrix_close(QuantumGate::RX(0.0).matrix(), identity);
+        assert_matrix_close(QuantumGate::RY(0.0).matrix(), identity);
+        assert_matrix_close(QuantumGate::RZ(0.0).matrix(), identity);
+    }
+
+    /// `RY(pi)` is the real rotation `[[0, -1], [1, 0]]`.
+    #[test]
+    fn ry_pi_matches_expected_matrix() {
+        let zero = Complex::new(0.0, 0.0);
+        let one = Complex::new(1.0, 0.0);
+        let expected = [[zero, -one], [one, zero]];
+        assert_matrix_close(QuantumGate::RY(std::f64::consts::PI).matrix(), expected);
+    }
+
+    /// `U1(lambda)` is the phase gate `diag(1, e^{i*lambda})`.
+    #[test]
+    fn u1_matches_expected_phase_matrix() {
+        let lambda = std::f64::consts::FRAC_PI_2;
+        let zero = Complex::new(0.0, 0.0);
+        let one = Complex::new(1.0, 0.0);
+        let expected = [[one, zero], [zero, Complex::new(0.0, lambda).exp()]];
+        assert_matrix_close(QuantumGate::U1(lambda).matrix(), expected);
+    }
+
+    /// `U3(0, 0, lambda)` is exactly `U1(lambda)`, as the doc comment on `u3` claims.
+    #[test]
+    fn u3_reduces_to_u1_special_case() {
+        let lambda = 0.37;
+        assert_matrix_close(
+            QuantumGate::U3(0.0, 0.0, lambda).matrix(),
+            QuantumGate::U1(lambda).matrix(),
+        );
+    }
+}
+
+impl fmt::Display for QuantumGate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuantumGate::H => write!(f, "H"),
+            QuantumGate::X => write!(f, "X"),
+            QuantumGate::Y => write!(f, "Y"),
+            QuantumGate::Z => write!(f, "Z"),
+            QuantumGate::S => write!(f, "S"),
+            QuantumGate::T => write!(f, "T"),
+            QuantumGate::CNOT => write!(f, "CNOT"),
+            QuantumGate::RX(theta) => write!(f, "RX({:.4})", theta),
+            QuantumGate::RY(theta) => write!(f, "RY({:.4})", theta),
+            QuantumGate::RZ(theta) => write!(f, "RZ({:.4})", theta),
+            QuantumGate::U1(lambda) => write!(f, "U1({:.4})", lambda),
+            QuantumGate::U3(theta, phi, lambda) => write!(f, "U3({:.4}, {:.4}, {:.4})", theta, phi, lambda),
+        }
+    }
+}
+
+/// A single scheduled gate application within a `QuantumCircuit`.
+#[derive(Clone, Copy, Debug)]
+pub struct GateOp {
+    pub gate: QuantumGate,
+    /// The qubit this operation acts on (the target, for controlled gates).
+    pub target: usize,
+    /// The control qubit, set for controlled gates such as `CNOT`.
+    pub control: Option<usize>,
+    /// The column this operation occupies on its target wire, used for
+    /// scheduling and for lining up the ASCII diagram.
+    pub step: usize,
+}
+
+impl GateOp {
+    /// Creates an uncontrolled single-qubit operation.
+    pub fn new(gate: QuantumGate, target: usize, step: usize) -> Self {
+        GateOp { gate, target, control: None, step }
+    }
+
+    /// Creates a controlled operation such as `CNOT`.
+    pub fn controlled(gate: QuantumGate, control: usize, target: usize, step: usize) -> Self {
+        GateOp { gate, target, control: Some(control), step }
+    }
+}
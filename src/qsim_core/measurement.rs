@@ -0,0 +1,7 @@
+/// The basis a qubit is measured, reset, or peeked in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Basis {
+    X,
+    Y,
+    Z,
+}
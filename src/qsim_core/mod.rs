@@ -0,0 +1,8 @@
+pub mod circuit;
+pub mod gate;
+pub mod measurement;
+pub mod operation;
+pub mod qasm;
+pub mod qubit;
+pub mod state_vector;
+pub mod zyz;
@@ -0,0 +1,59 @@
+use crate::qsim_core::gate::GateOp;
+use crate::qsim_core::measurement::Basis;
+
+/// A single scheduled instruction within a `QuantumCircuit`: either a
+/// unitary gate or a measurement-related instruction on a qubit.
+#[derive(Clone, Debug)]
+pub enum Operation {
+    Gate(GateOp),
+    Measure { qubit: usize, basis: Basis, step: usize },
+    Reset { qubit: usize, step: usize },
+    Peek { qubit: usize, basis: Basis, step: usize },
+    /// `gate` fires only if the classical register bits at `bits` (written
+    /// by an earlier `Measure`), read most-significant first, equal `value`.
+    ConditionalGate { bits: Vec<usize>, value: usize, gate: GateOp },
+}
+
+impl Operation {
+    /// The qubit wire this operation is scheduled on.
+    pub fn target(&self) -> usize {
+        match self {
+            Operation::Gate(op) => op.target,
+            Operation::Measure { qubit, .. } => *qubit,
+            Operation::Reset { qubit, .. } => *qubit,
+            Operation::Peek { qubit, .. } => *qubit,
+            Operation::ConditionalGate { gate, .. } => gate.target,
+        }
+    }
+
+    /// The column this operation occupies on its target wire.
+    pub fn step(&self) -> usize {
+        match self {
+            Operation::Gate(op) => op.step,
+            Operation::Measure { step, .. } => *step,
+            Operation::Reset { step, .. } => *step,
+            Operation::Peek { step, .. } => *step,
+            Operation::ConditionalGate { gate, .. } => gate.step,
+        }
+    }
+
+    /// The control qubit, if any. Only `Gate` and `ConditionalGate` operations can be controlled.
+    pub fn control(&self) -> Option<usize> {
+        match self {
+            Operation::Gate(op) => op.control,
+            Operation::ConditionalGate { gate, .. } => gate.control,
+            _ => None,
+        }
+    }
+
+    /// The symbol drawn for this operation in `QuantumCircuit::display`.
+    pub fn diagram_symbol(&self) -> String {
+        match self {
+            Operation::Gate(op) => op.gate.to_string(),
+            Operation::Measure { basis, .. } => format!("M{:?}", basis),
+            Operation::Reset { .. } => "|0>".to_string(),
+            Operation::Peek { basis, .. } => format!("?{:?}", basis),
+            Operation::ConditionalGate { gate, .. } => format!("{}?", gate.gate),
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use std::fmt;
+
+use crate::qsim_core::circuit::QuantumCircuit;
+use crate::qsim_core::measurement::Basis;
+
+/// An error encountered while parsing an OpenQASM 2.0 source string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum QasmError {
+    MissingHeader,
+    MissingQreg,
+    UnknownGate(String),
+    MalformedStatement(String),
+}
+
+impl fmt::Display for QasmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QasmError::MissingHeader => write!(f, "missing 'OPENQASM 2.0;' header"),
+            QasmError::MissingQreg => write!(f, "missing 'qreg' declaration"),
+            QasmError::UnknownGate(name) => write!(f, "unknown gate '{}'", name),
+            QasmError::MalformedStatement(stmt) => write!(f, "malformed statement: '{}'", stmt),
+        }
+    }
+}
+
+impl std::error::Error for QasmError {}
+
+/// Parses an OpenQASM 2.0 source string into a `QuantumCircuit`.
+///
+/// Supports the subset emitted by `QuantumCircuit::to_openqasm`: a header, a
+/// single `qreg` declaration, and one statement per line among
+/// `h`/`x`/`y`/`z`/`s`/`t`/`cx`/`rx`/`ry`/`rz`/`u1`/`u3`/`measure`/`reset`.
+/// Comments (`//`) are ignored.
+pub fn parse(src: &str) -> Result<QuantumCircuit, QasmError> {
+    let mut saw_header = false;
+    let mut num_qubits = None;
+    let mut statements = Vec::new();
+
+    for raw_line in src.lines() {
+        let line = raw_line.split("//").next().unwrap_or("").trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line.starts_with("OPENQASM") {
+            saw_header = true;
+            continue;
+        }
+        if line.starts_with("include") || line.starts_with("creg") {
+            continue;
+        }
+        let line = line.strip_suffix(';').unwrap_or(line);
+
+        if let Some(rest) = line.strip_prefix("qreg") {
+            num_qubits = Some(parse_reg_size(rest.trim(), raw_line)?);
+            continue;
+        }
+
+        statements.push(line.to_string());
+    }
+
+    if !saw_header {
+        return Err(QasmError::MissingHeader);
+    }
+    let num_qubits = num_qubits.ok_or(QasmError::MissingQreg)?;
+    let mut qc = QuantumCircuit::new(num_qubits);
+
+    for stmt in &statements {
+        apply_statement(&mut qc, stmt)?;
+    }
+
+    Ok(qc)
+}
+
+fn parse_reg_size(decl: &str, stmt: &str) -> Result<usize, QasmError> {
+    decl.trim_start_matches(|c: char| c.is_alphabetic())
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<usize>()
+        .map_err(|_| QasmError::MalformedStatement(stmt.to_string()))
+}
+
+fn apply_statement(qc: &mut QuantumCircuit, stmt: &str) -> Result<(), QasmError> {
+    if let Some(rest) = stmt.strip_prefix("measure") {
+        let qubit = parse_qubit_index(
+            rest.split("->").next().unwrap_or("").trim(),
+            stmt,
+        )?;
+        qc.measure(qubit, Basis::Z);
+        return Ok(());
+    }
+    if let Some(rest) = stmt.strip_prefix("reset") {
+        qc.reset(parse_qubit_index(rest.trim(), stmt)?);
+        return Ok(());
+    }
+
+    // A parametric gate's parameter list can itself contain spaces (e.g.
+    // `u3(1.1, 0.2, -0.9) q[0]`), so the name/params can't be split from the
+    // operands on the first space; split after the list's closing `)` instead,
+    // falling back to the first space for gates with no parameters at all.
+    let (name_and_params, operands) = match stmt.find(')') {
+        Some(close_paren) => {
+            let (head, tail) = stmt.split_at(close_paren + 1);
+            (head, tail.trim_start())
+        }
+        None => stmt
+            .split_once(' ')
+            .ok_or_else(|| QasmError::MalformedStatement(stmt.to_string()))?,
+    };
+    let (name, params) = match name_and_params.split_once('(') {
+        Some((name, rest)) => {
+            let params = rest
+                .trim_end_matches(')')
+                .split(',')
+                .map(|p| {
+                    p.trim()
+                        .parse::<f64>()
+                        .map_err(|_| QasmError::MalformedStatement(stmt.to_string()))
+                })
+                .collect::<Result<Vec<f64>, QasmError>>()?;
+            (name, params)
+        }
+        None => (name_and_params, Vec::new()),
+    };
+
+    let qubits: Vec<usize> = operands
+        .split(',')
+        .map(|q| parse_qubit_index(q.trim(), stmt))
+        .collect::<Result<_, _>>()?;
+
+    match (name, qubits.as_slice(), params.as_slice()) {
+        ("h", [t], []) => qc.h(*t),
+        ("x", [t], []) => qc.x(*t),
+        ("y", [t], []) => qc.y(*t),
+        ("z", [t], []) => qc.z(*t),
+        ("s", [t], []) => qc.s(*t),
+        ("t", [t], []) => qc.t(*t),
+        ("cx", [c, t], []) => qc.cnot(*c, *t),
+        ("rx", [t], [theta]) => qc.rx(*theta, *t),
+        ("ry", [t], [theta]) => qc.ry(*theta, *t),
+        ("rz", [t], [theta]) => qc.rz(*theta, *t),
+        ("u1", [t], [lambda]) => qc.u1(*lambda, *t),
+        ("u3", [t], [theta, phi, lambda]) => qc.u3(*theta, *phi, *lambda, *t),
+        (other, _, _) => return Err(QasmError::UnknownGate(other.to_string())),
+    }
+    Ok(())
+}
+
+fn parse_qubit_index(token: &str, stmt: &str) -> Result<usize, QasmError> {
+    token
+        .trim()
+        .trim_start_matches(|c: char| c.is_alphabetic())
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<usize>()
+        .map_err(|_| QasmError::MalformedStatement(stmt.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A circuit round-tripped through `to_openqasm`/`from_openqasm` must come
+    /// back with the same qubit count and the same number of operations.
+    #[test]
+    fn bell_circuit_round_trips_through_openqasm() {
+        let mut qc = QuantumCircuit::new(2);
+        qc.h(0);
+        qc.cnot(0, 1);
+        qc.measure(0, Basis::Z);
+
+        let src = qc.to_openqasm();
+        let parsed = QuantumCircuit::from_openqasm(&src).expect("round-trip should parse");
+
+        assert_eq!(parsed.num_qubits(), qc.num_qubits());
+        assert_eq!(parsed.num_operations(), qc.num_operations());
+    }
+
+    /// `u3`'s parameter list contains comma-spaces (`u3(1.1, 0.2, -0.9) q[0];`),
+    /// which once confused `apply_statement`'s name/operand split; make sure a
+    /// multi-parameter gate round-trips too, not just zero-parameter ones.
+    #[test]
+    fn multi_param_gate_round_trips_through_openqasm() {
+        let mut qc = QuantumCircuit::new(1);
+        qc.u3(1.1, 0.2, -0.9, 0);
+        qc.u1(0.5, 0);
+
+        let src = qc.to_openqasm();
+        let parsed = QuantumCircuit::from_openqasm(&src).expect("round-trip should parse");
+
+        assert_eq!(parsed.num_qubits(), qc.num_qubits());
+        assert_eq!(parsed.num_operations(), qc.num_operations());
+    }
+
+    #[test]
+    fn parse_rejects_missing_header() {
+        let src = "qreg q[1];\nh q[0];\n";
+        assert_eq!(parse(src).unwrap_err(), QasmError::MissingHeader);
+    }
+
+    #[test]
+    fn parse_rejects_missing_qreg() {
+        let src = "OPENQASM 2.0;\ninclude \"qelib1.inc\";\nh q[0];\n";
+        assert_eq!(parse(src).unwrap_err(), QasmError::MissingQreg);
+    }
+
+    #[test]
+    fn parse_rejects_unknown_gate() {
+        let src = "OPENQASM 2.0;\nqreg q[1];\nfrobnicate q[0];\n";
+        assert_eq!(parse(src).unwrap_err(), QasmError::UnknownGate("frobnicate".to_string()));
+    }
+
+    #[test]
+    fn parse_rejects_malformed_statement() {
+        let src = "OPENQASM 2.0;\nqreg q[1];\nh;\n";
+        assert_eq!(parse(src).unwrap_err(), QasmError::MalformedStatement("h".to_string()));
+    }
+}
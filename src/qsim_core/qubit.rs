@@ -0,0 +1,41 @@
+use num_complex::Complex;
+
+use crate::qsim_core::gate::QuantumGate;
+
+/// A single qubit, represented by its state vector `[amplitude of |0>, amplitude of |1>]`.
+///
+/// Each `Qubit` is independent of every other one, so this representation
+/// cannot express entanglement between qubits. Circuits that need joint
+/// multi-qubit behaviour (e.g. `cnot`) should use
+/// `crate::qsim_core::state_vector::StateVector` instead.
+#[derive(Clone, Copy, Debug)]
+pub struct Qubit {
+    amplitudes: [Complex<f64>; 2],
+}
+
+impl Qubit {
+    /// Creates a qubit initialised to the |0> state.
+    pub fn zero() -> Self {
+        Qubit {
+            amplitudes: [Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)],
+        }
+    }
+
+    /// Applies a single-qubit gate to this qubit in place.
+    ///
+    /// # Panics
+    /// Panics if `gate` is a multi-qubit gate such as `CNOT`.
+    pub fn apply(&mut self, gate: QuantumGate) {
+        let m = gate.matrix();
+        let [a0, a1] = self.amplitudes;
+        self.amplitudes = [
+            m[0][0] * a0 + m[0][1] * a1,
+            m[1][0] * a0 + m[1][1] * a1,
+        ];
+    }
+
+    /// Returns the `[|0>, |1>]` amplitude pair for this qubit.
+    pub fn amplitudes(&self) -> [Complex<f64>; 2] {
+        self.amplitudes
+    }
+}
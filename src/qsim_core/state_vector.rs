@@ -0,0 +1,357 @@
+use num_complex::Complex;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::qsim_core::gate::{GateOp, QuantumGate};
+use crate::qsim_core::measurement::Basis;
+
+/// Below this many qubits, the amplitude vector is small enough that the
+/// sequential path in `StateVector::apply_gate` outruns the overhead of
+/// handing blocks to rayon's thread pool. Only consulted when the
+/// `parallel` feature is enabled.
+#[cfg(feature = "parallel")]
+const PARALLEL_QUBIT_THRESHOLD: usize = 12;
+
+/// The joint amplitude vector of an `n`-qubit system: `2^n` complex
+/// amplitudes, indexed by basis state with qubit 0 as the most significant
+/// bit.
+///
+/// Unlike `crate::qsim_core::qubit::Qubit`, which models each qubit
+/// independently, `StateVector` holds a single entangled state, so gates
+/// like `cnot` correctly produce correlations between qubits. It also owns
+/// the classical register that `measure` writes outcomes into.
+#[derive(Clone, Debug)]
+pub struct StateVector {
+    num_qubits: usize,
+    amplitudes: Vec<Complex<f64>>,
+    classical: Vec<Option<bool>>,
+    peeks: Vec<(usize, bool)>,
+}
+
+impl StateVector {
+    /// Creates a state vector for `num_qubits` qubits, initialised to |00...0>.
+    pub fn new(num_qubits: usize) -> Self {
+        let mut amplitudes = vec![Complex::new(0.0, 0.0); 1 << num_qubits];
+        amplitudes[0] = Complex::new(1.0, 0.0);
+        StateVector {
+            num_qubits,
+            amplitudes,
+            classical: vec![None; num_qubits],
+            peeks: Vec::new(),
+        }
+    }
+
+    /// Returns the number of qubits this state vector represents.
+    pub fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    /// Returns the raw amplitude array, indexed by basis state.
+    pub fn amplitudes(&self) -> &[Complex<f64>] {
+        &self.amplitudes
+    }
+
+    /// Returns the classical register: `Some(outcome)` for each qubit that
+    /// has been measured, `None` for qubits that haven't.
+    pub fn classical_bits(&self) -> &[Option<bool>] {
+        &self.classical
+    }
+
+    /// Returns the outcomes sampled by `peek`, in the order they were taken,
+    /// as `(qubit, outcome)` pairs. Unlike `classical_bits`, this isn't
+    /// indexed by qubit, since a qubit can be peeked more than once and a
+    /// peek never writes the classical register.
+    pub fn peeks(&self) -> &[(usize, bool)] {
+        &self.peeks
+    }
+
+    /// Applies an arbitrary `k`-qubit unitary to the given qubits.
+    ///
+    /// `matrix` is a `2^k x 2^k` matrix in row-major order, and
+    /// `affected_bits` names the `k` qubits it acts on, most-significant
+    /// first. Each amplitude index is split into the bits named in
+    /// `affected_bits` (its "local" position within the block) and the
+    /// remaining, untouched bits (its "block"); every block of `2^k`
+    /// amplitudes sharing the same untouched bits is gathered into a
+    /// contiguous vector, multiplied by `matrix`, and scattered back. This
+    /// single code path covers single-qubit gates, `cnot`, `swap`, `toffoli`,
+    /// and any other custom gate.
+    ///
+    /// # Panics
+    /// Panics if `matrix` is not `2^k x 2^k` for `k = affected_bits.len()`.
+    pub fn apply_gate(&mut self, matrix: &[Complex<f64>], affected_bits: &[usize]) {
+        let k = affected_bits.len();
+        let dim = 1usize << k;
+        assert_eq!(
+            matrix.len(),
+            dim * dim,
+            "matrix must be {0}x{0} for {1} affected qubit(s)",
+            dim,
+            k
+        );
+
+        let masks: Vec<usize> = affected_bits
+            .iter()
+            .map(|&b| 1usize << (self.num_qubits - 1 - b))
+            .collect();
+        let combined_mask = masks.iter().fold(0, |acc, &m| acc | m);
+
+        // `global_index` reconstructs the full amplitude index for a block
+        // anchored at `base` (all affected bits cleared) given a `key` that
+        // packs the affected bits in the order listed in `affected_bits`.
+        let global_index = |base: usize, key: usize| -> usize {
+            let mut idx = base;
+            for (p, &mask) in masks.iter().enumerate() {
+                if (key >> (k - 1 - p)) & 1 == 1 {
+                    idx |= mask;
+                }
+            }
+            idx
+        };
+
+        // Only the representative with every affected bit cleared processes
+        // its block, so each block is handled exactly once; distinct blocks
+        // never share an amplitude index, so they can be multiplied
+        // independently.
+        let bases = (0..self.amplitudes.len()).filter(|base| base & combined_mask == 0);
+
+        #[cfg(feature = "parallel")]
+        if self.num_qubits >= PARALLEL_QUBIT_THRESHOLD {
+            // The affected bits aren't generally contiguous in the index
+            // space, so the blocks can't be handed to the thread pool as
+            // literal `par_chunks_mut` slices; instead each block's gather
+            // and multiply (the expensive `2^k`-wide work) runs in
+            // parallel over shared, read-only access to `self.amplitudes`,
+            // and the results are scattered back sequentially afterwards,
+            // since that step is only `O(amplitudes.len())` and touches
+            // disjoint indices per block anyway.
+            let new_blocks: Vec<(usize, Vec<Complex<f64>>)> = bases
+                .collect::<Vec<usize>>()
+                .into_par_iter()
+                .map(|base| {
+                    let block: Vec<Complex<f64>> = (0..dim)
+                        .map(|key| self.amplitudes[global_index(base, key)])
+                        .collect();
+                    let new_block = (0..dim)
+                        .map(|row| {
+                            block
+                                .iter()
+                                .enumerate()
+                                .fold(Complex::new(0.0, 0.0), |sum, (col, amp)| {
+                                    sum + matrix[row * dim + col] * amp
+                                })
+                        })
+                        .collect();
+                    (base, new_block)
+                })
+                .collect();
+            for (base, new_block) in new_blocks {
+                for (row, amp) in new_block.into_iter().enumerate() {
+                    self.amplitudes[global_index(base, row)] = amp;
+                }
+            }
+            return;
+        }
+
+        for base in bases {
+            let block: Vec<Complex<f64>> = (0..dim)
+                .map(|key| self.amplitudes[global_index(base, key)])
+                .collect();
+
+            for row in 0..dim {
+                let mut sum = Complex::new(0.0, 0.0);
+                for (col, amp) in block.iter().enumerate() {
+                    sum += matrix[row * dim + col] * amp;
+                }
+                self.amplitudes[global_index(base, row)] = sum;
+            }
+        }
+    }
+
+    /// Applies a single scheduled gate operation to the state vector.
+    pub fn apply_op(&mut self, op: &GateOp) {
+        match (op.gate, op.control) {
+            (QuantumGate::CNOT, Some(control)) => {
+                self.apply_gate(&cnot_matrix(), &[control, op.target]);
+            }
+            (QuantumGate::CNOT, None) => panic!("CNOT operation is missing its control qubit"),
+            (gate, _) => {
+                let m = gate.matrix();
+                self.apply_gate(&[m[0][0], m[0][1], m[1][0], m[1][1]], &[op.target]);
+            }
+        }
+    }
+
+    /// Measures `qubit` in the given basis, collapsing the state and
+    /// recording the outcome in the classical register.
+    ///
+    /// `X`/`Y` bases are measured by rotating into the `Z` basis, collapsing,
+    /// then rotating back, so the post-measurement state stays expressed in
+    /// the original basis.
+    pub fn measure(&mut self, qubit: usize, basis: Basis) -> bool {
+        if let Some(forward) = basis_rotation(basis) {
+            self.apply_gate(&forward, &[qubit]);
+        }
+        let outcome = self.collapse_z(qubit);
+        if let Some(backward) = basis_rotation(basis).map(conjugate_transpose) {
+            self.apply_gate(&backward, &[qubit]);
+        }
+        self.classical[qubit] = Some(outcome);
+        outcome
+    }
+
+    /// Samples a measurement outcome for `qubit` in the given basis without
+    /// collapsing the state or touching the classical register. The outcome
+    /// is appended to `peeks`, since it isn't recorded anywhere else.
+    pub fn peek(&mut self, qubit: usize, basis: Basis) -> bool {
+        let outcome = self.clone().measure(qubit, basis);
+        self.peeks.push((qubit, outcome));
+        outcome
+    }
+
+    /// Collapses `qubit` to |0>, regardless of its current state. Unlike
+    /// `measure`, this does not record an outcome in the classical register.
+    pub fn reset(&mut self, qubit: usize) {
+        if self.collapse_z(qubit) {
+            let m = QuantumGate::X.matrix();
+            self.apply_gate(&[m[0][0], m[0][1], m[1][0], m[1][1]], &[qubit]);
+        }
+    }
+
+    /// Collapses `qubit` in the Z basis: samples an outcome weighted by
+    /// `|amplitude|^2`, zeroes the amplitudes inconsistent with it, and
+    /// renormalizes the remainder. Returns the sampled outcome.
+    fn collapse_z(&mut self, qubit: usize) -> bool {
+        let mask = 1usize << (self.num_qubits - 1 - qubit);
+        let prob_zero: f64 = self
+            .amplitudes
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| i & mask == 0)
+            .map(|(_, a)| a.norm_sqr())
+            .sum();
+
+        let outcome = rand::random::<f64>() >= prob_zero;
+        let norm = if outcome {
+            (1.0 - prob_zero).sqrt()
+        } else {
+            prob_zero.sqrt()
+        };
+
+        for (i, amp) in self.amplitudes.iter_mut().enumerate() {
+            if (i & mask != 0) == outcome {
+                *amp /= norm;
+            } else {
+                *amp = Complex::new(0.0, 0.0);
+            }
+        }
+        outcome
+    }
+}
+
+/// The 4x4 CNOT matrix over `[control, target]`, in row-major order.
+fn cnot_matrix() -> [Complex<f64>; 16] {
+    let zero = Complex::new(0.0, 0.0);
+    let one = Complex::new(1.0, 0.0);
+    [
+        one, zero, zero, zero, //
+        zero, one, zero, zero, //
+        zero, zero, zero, one, //
+        zero, zero, one, zero, //
+    ]
+}
+
+/// The 2x2 matrix that rotates the given basis's eigenstates onto the Z
+/// basis, so a Z-basis collapse measures in `basis`. `None` for `Z` itself,
+/// since no rotation is needed.
+fn basis_rotation(basis: Basis) -> Option<[Complex<f64>; 4]> {
+    match basis {
+        Basis::Z => None,
+        Basis::X => {
+            let h = QuantumGate::H.matrix();
+            Some([h[0][0], h[0][1], h[1][0], h[1][1]])
+        }
+        Basis::Y => {
+            // H * Sdag, mapping the Y eigenstates |+i>, |-i> onto |0>, |1>.
+            let frac = Complex::new(std::f64::consts::FRAC_1_SQRT_2, 0.0);
+            let neg_i_frac = Complex::new(0.0, -std::f64::consts::FRAC_1_SQRT_2);
+            let pos_i_frac = Complex::new(0.0, std::f64::consts::FRAC_1_SQRT_2);
+            Some([frac, neg_i_frac, frac, pos_i_frac])
+        }
+    }
+}
+
+/// The conjugate transpose of a 2x2 row-major matrix, i.e. its inverse for
+/// the unitary matrices `basis_rotation` produces.
+fn conjugate_transpose(m: [Complex<f64>; 4]) -> [Complex<f64>; 4] {
+    [m[0].conj(), m[2].conj(), m[1].conj(), m[3].conj()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::qsim_core::gate::GateOp;
+
+    fn assert_close(actual: Complex<f64>, expected: Complex<f64>) {
+        assert!(
+            (actual - expected).norm() < 1e-9,
+            "expected {:?}, got {:?}",
+            expected,
+            actual
+        );
+    }
+
+    /// `h(0); cnot(0, 1)` on an independent-qubit model leaves the qubits
+    /// uncorrelated; on the joint state vector it must produce the entangled
+    /// Bell pair `(|00> + |11>) / sqrt(2)`.
+    #[test]
+    fn cnot_entangles_the_joint_state_into_a_bell_pair() {
+        let mut state = StateVector::new(2);
+        state.apply_op(&GateOp::new(QuantumGate::H, 0, 0));
+        state.apply_op(&GateOp::controlled(QuantumGate::CNOT, 0, 1, 1));
+
+        let frac = Complex::new(std::f64::consts::FRAC_1_SQRT_2, 0.0);
+        let zero = Complex::new(0.0, 0.0);
+        let amps = state.amplitudes();
+        assert_close(amps[0b00], frac);
+        assert_close(amps[0b01], zero);
+        assert_close(amps[0b10], zero);
+        assert_close(amps[0b11], frac);
+    }
+
+    /// A three-qubit GHZ chain (`h(0); cnot(0, 1); cnot(1, 2)`) should collapse
+    /// to `(|000> + |111>) / sqrt(2)`, exercising two successive applications
+    /// of `apply_gate` against overlapping, adjacent affected-bit pairs.
+    #[test]
+    fn ghz_chain_entangles_three_qubits() {
+        let mut state = StateVector::new(3);
+        state.apply_op(&GateOp::new(QuantumGate::H, 0, 0));
+        state.apply_op(&GateOp::controlled(QuantumGate::CNOT, 0, 1, 1));
+        state.apply_op(&GateOp::controlled(QuantumGate::CNOT, 1, 2, 2));
+
+        let frac = Complex::new(std::f64::consts::FRAC_1_SQRT_2, 0.0);
+        let amps = state.amplitudes();
+        for (i, &amp) in amps.iter().enumerate() {
+            let expected = if i == 0b000 || i == 0b111 { frac } else { Complex::new(0.0, 0.0) };
+            assert_close(amp, expected);
+        }
+    }
+
+    /// `apply_gate` must reorder non-adjacent affected bits correctly: a CNOT
+    /// between qubits 0 and 2 (skipping qubit 1 entirely) should entangle
+    /// exactly those two wires, leaving qubit 1 untouched at |0>.
+    #[test]
+    fn apply_gate_handles_non_adjacent_affected_bits() {
+        let mut state = StateVector::new(3);
+        state.apply_op(&GateOp::new(QuantumGate::H, 0, 0));
+        state.apply_op(&GateOp::controlled(QuantumGate::CNOT, 0, 2, 1));
+
+        let frac = Complex::new(std::f64::consts::FRAC_1_SQRT_2, 0.0);
+        let amps = state.amplitudes();
+        for (i, &amp) in amps.iter().enumerate() {
+            // |000> and |101>: qubit 0 and qubit 2 flip together, qubit 1 stays 0.
+            let expected = if i == 0b000 || i == 0b101 { frac } else { Complex::new(0.0, 0.0) };
+            assert_close(amp, expected);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use num_complex::Complex;
+
+/// The 2x2 identity matrix.
+pub fn identity() -> [[Complex<f64>; 2]; 2] {
+    let zero = Complex::new(0.0, 0.0);
+    let one = Complex::new(1.0, 0.0);
+    [[one, zero], [zero, one]]
+}
+
+/// Multiplies two 2x2 matrices: `a * b`.
+pub fn mat_mul(a: [[Complex<f64>; 2]; 2], b: [[Complex<f64>; 2]; 2]) -> [[Complex<f64>; 2]; 2] {
+    let mut out = [[Complex::new(0.0, 0.0); 2]; 2];
+    for row in 0..2 {
+        for col in 0..2 {
+            out[row][col] = a[row][0] * b[0][col] + a[row][1] * b[1][col];
+        }
+    }
+    out
+}
+
+/// A ZYZ Euler decomposition of a single-qubit unitary
+/// (`RZ(phi) * RY(theta) * RZ(lambda)`, up to an unmodelled global phase),
+/// with the degenerate `theta` cases collapsed to fewer rotations.
+#[derive(Clone, Copy, Debug)]
+pub enum ZyzDecomposition {
+    /// `theta` is ~0 (mod 2*pi): the unitary is a pure Z rotation.
+    SingleRz(f64),
+    /// `theta` is ~pi (mod 2*pi): `RY(pi)` followed by one combined Z rotation.
+    PiRotation(f64),
+    /// The general `RZ(lambda)` then `RY(theta)` then `RZ(phi)` triple.
+    Full { lambda: f64, theta: f64, phi: f64 },
+}
+
+const EPSILON: f64 = 1e-9;
+
+/// Decomposes a 2x2 unitary `matrix` into a ZYZ Euler triple.
+///
+/// `matrix` need not be special-unitary: its overall determinant is treated
+/// as an unmodelled global phase and divided out (`alpha` below) before `phi`
+/// and `lambda` are read off, since halving a phase that is still mixed with
+/// that global phase would otherwise put the result on the wrong branch.
+/// `theta` is recovered from `|U00|` and `|U10|`. See `QuantumCircuit::optimize`.
+pub fn decompose(matrix: [[Complex<f64>; 2]; 2]) -> ZyzDecomposition {
+    let u00 = matrix[0][0];
+    let u01 = matrix[0][1];
+    let u10 = matrix[1][0];
+    let u11 = matrix[1][1];
+
+    let theta = 2.0 * u10.norm().atan2(u00.norm());
+
+    if theta.abs() < EPSILON {
+        return ZyzDecomposition::SingleRz((u11 / u00).arg());
+    }
+    if (theta - std::f64::consts::PI).abs() < EPSILON {
+        // Up to global phase, U10 = e^{i*diff/2} and U01 = -e^{-i*diff/2}, so
+        // their ratio yields `diff` with that phase cancelled out.
+        return ZyzDecomposition::PiRotation((u10 / -u01).arg());
+    }
+
+    let det = u00 * u11 - u01 * u10;
+    let alpha = 0.5 * det.arg();
+    let phase = Complex::new(0.0, alpha).exp();
+    let m00 = u00 / phase;
+    let m10 = u10 / phase;
+
+    let phi = m10.arg() - m00.arg();
+    let lambda = -m00.arg() - m10.arg();
+
+    ZyzDecomposition::Full { lambda, theta, phi }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::qsim_core::gate::QuantumGate;
+
+    fn assert_angle_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected angle {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    /// The Z gate (`diag(1, -1)`) has `theta == 0`, so it must decompose into
+    /// a single RZ rotation rather than the general three-rotation form.
+    #[test]
+    fn pure_phase_gate_decomposes_to_single_rz() {
+        let one = Complex::new(1.0, 0.0);
+        let zero = Complex::new(0.0, 0.0);
+        let z = [[one, zero], [zero, -one]];
+
+        match decompose(z) {
+            ZyzDecomposition::SingleRz(angle) => assert_angle_close(angle, std::f64::consts::PI),
+            other => panic!("expected SingleRz, got {:?}", other),
+        }
+    }
+
+    /// The X gate (`theta == pi`) must take the degenerate `RY(pi)` branch
+    /// instead of the general form; `RZ(pi) * RY(pi)` is `i * X`.
+    #[test]
+    fn x_gate_decomposes_to_pi_rotation() {
+        let one = Complex::new(1.0, 0.0);
+        let zero = Complex::new(0.0, 0.0);
+        let x = [[zero, one], [one, zero]];
+
+        match decompose(x) {
+            ZyzDecomposition::PiRotation(diff) => assert_angle_close(diff, std::f64::consts::PI),
+            other => panic!("expected PiRotation, got {:?}", other),
+        }
+    }
+
+    /// A generic angle strictly between the two degenerate cases must take
+    /// the `Full` branch and recover the same `theta` the matrix was built
+    /// from.
+    #[test]
+    fn generic_unitary_decomposes_to_full_triple() {
+        let theta = 1.2;
+        let phi = 0.4;
+        let lambda = 0.7;
+        let matrix = QuantumGate::U3(theta, phi, lambda).matrix();
+
+        match decompose(matrix) {
+            ZyzDecomposition::Full { theta: recovered, .. } => assert_angle_close(recovered, theta),
+            other => panic!("expected Full, got {:?}", other),
+        }
+    }
+}